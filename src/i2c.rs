@@ -19,10 +19,29 @@ crate::embedded_hal_error!(
     embedded_hal::i2c::ErrorKind
 );
 
+#[cfg(feature = "defmt")]
+impl defmt::Format for I2cError {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        defmt::write!(
+            f,
+            "I2cError {{ kind: {}, code: {} }}",
+            embedded_hal::i2c::Error::kind(self),
+            self.cause().code()
+        )
+    }
+}
+
 #[cfg(any(esp32, esp32s2))]
 const APB_TICK_PERIOD_NS: u32 = 1_000_000_000 / 80_000_000;
 #[cfg(not(any(esp32, esp32s2)))]
 const XTAL_TICK_PERIOD_NS: u32 = 1_000_000_000 / XTAL_CLK_FREQ;
+// The glitch filter counts cycles of the I2C source clock, which the legacy driver runs from APB
+// on the esp32 and esp32s2, and from XTAL on the other chips, the same as `APBTickType` assumes
+#[cfg(any(esp32, esp32s2))]
+const SCLK_PERIOD_NS: u32 = APB_TICK_PERIOD_NS;
+#[cfg(not(any(esp32, esp32s2)))]
+const SCLK_PERIOD_NS: u32 = XTAL_TICK_PERIOD_NS;
+
 #[derive(Copy, Clone, Debug)]
 pub struct APBTickType(::core::ffi::c_int);
 impl From<Duration> for APBTickType {
@@ -65,8 +84,10 @@ pub type I2cSlaveConfig = config::SlaveConfig;
 pub mod config {
     use enumset::EnumSet;
 
-    use super::APBTickType;
-    use crate::{interrupt::InterruptType, units::*};
+    use core::time::Duration;
+
+    use super::{APBTickType, MAX_GLITCH_IGNORE_COUNT, SCLK_PERIOD_NS};
+    use crate::{gpio::DriveStrength, interrupt::InterruptType, units::*};
 
     /// I2C Master configuration
     #[derive(Debug, Clone)]
@@ -76,6 +97,8 @@ pub mod config {
         pub scl_pullup_enabled: bool,
         pub timeout: Option<APBTickType>,
         pub intr_flags: EnumSet<InterruptType>,
+        pub drive_strength: Option<DriveStrength>,
+        pub glitch_ignore_count: Option<u8>,
     }
 
     impl Config {
@@ -83,6 +106,21 @@ pub mod config {
             Default::default()
         }
 
+        /// Standard mode (100 kHz)
+        pub fn standard_mode() -> Self {
+            Self::new().baudrate(Hertz(100_000))
+        }
+
+        /// Fast mode (400 kHz)
+        pub fn fast_mode() -> Self {
+            Self::new().baudrate(Hertz(400_000))
+        }
+
+        /// Fast mode plus (1 MHz)
+        pub fn fast_mode_plus() -> Self {
+            Self::new().baudrate(Hertz(1_000_000))
+        }
+
         #[must_use]
         pub fn baudrate(mut self, baudrate: Hertz) -> Self {
             self.baudrate = baudrate;
@@ -112,6 +150,47 @@ pub mod config {
             self.intr_flags = flags;
             self
         }
+
+        /// Drive strength of the SDA and SCL pins; the pins' current drive strength is left
+        /// untouched if not set
+        ///
+        /// When [`I2cDriver::reconfigure`](super::I2cDriver::reconfigure) replaces a configuration
+        /// which did set a drive strength with one which does not, the GPIO default of
+        /// `DriveStrength::I20mA` is restored.
+        ///
+        /// A stronger drive shortens the falling edges on long or heavily loaded lines,
+        /// at the cost of more ringing. Rising edges still depend on the pullups.
+        #[must_use]
+        pub fn drive_strength(mut self, strength: DriveStrength) -> Self {
+            self.drive_strength = Some(strength);
+            self
+        }
+
+        /// Length of the SDA/SCL glitch filter: pulses shorter than `count` I2C source clock
+        /// cycles are ignored
+        ///
+        /// Valid values are 0 (filter disabled) up to 7; larger values are rejected with
+        /// `ESP_ERR_INVALID_ARG` when the driver is installed. If not set, the IDF default of 7
+        /// cycles is used.
+        #[must_use]
+        pub fn glitch_ignore_count(mut self, count: u8) -> Self {
+            self.glitch_ignore_count = Some(count);
+            self
+        }
+
+        /// Same as [`Config::glitch_ignore_count`], but specifies the longest pulse to ignore
+        /// as a duration
+        ///
+        /// The duration is rounded up to whole I2C source clock cycles (APB on the esp32 and
+        /// esp32s2, XTAL on the other chips) and clamped to the 7 cycles supported by the
+        /// hardware. A zero duration disables the filter.
+        #[must_use]
+        pub fn glitch_filter(self, duration: Duration) -> Self {
+            let cycles =
+                (duration.as_nanos() + SCLK_PERIOD_NS as u128 - 1) / SCLK_PERIOD_NS as u128;
+
+            self.glitch_ignore_count(cycles.min(MAX_GLITCH_IGNORE_COUNT as u128) as u8)
+        }
     }
 
     impl Default for Config {
@@ -122,141 +201,943 @@ pub mod config {
                 scl_pullup_enabled: true,
                 timeout: None,
                 intr_flags: EnumSet::<InterruptType>::empty(),
+                drive_strength: None,
+                glitch_ignore_count: None,
+            }
+        }
+    }
+
+    /// I2C Slave configuration
+    #[cfg(not(esp32c2))]
+    #[derive(Debug, Clone)]
+    pub struct SlaveConfig {
+        pub sda_pullup_enabled: bool,
+        pub scl_pullup_enabled: bool,
+        pub rx_buf_len: usize,
+        pub tx_buf_len: usize,
+        pub intr_flags: EnumSet<InterruptType>,
+    }
+
+    #[cfg(not(esp32c2))]
+    impl SlaveConfig {
+        pub fn new() -> Self {
+            Default::default()
+        }
+
+        #[must_use]
+        pub fn sda_enable_pullup(mut self, enable: bool) -> Self {
+            self.sda_pullup_enabled = enable;
+            self
+        }
+
+        #[must_use]
+        pub fn scl_enable_pullup(mut self, enable: bool) -> Self {
+            self.scl_pullup_enabled = enable;
+            self
+        }
+
+        #[must_use]
+        pub fn rx_buffer_length(mut self, len: usize) -> Self {
+            self.rx_buf_len = len;
+            self
+        }
+
+        #[must_use]
+        pub fn tx_buffer_length(mut self, len: usize) -> Self {
+            self.tx_buf_len = len;
+            self
+        }
+
+        #[must_use]
+        pub fn intr_flags(mut self, flags: EnumSet<InterruptType>) -> Self {
+            self.intr_flags = flags;
+            self
+        }
+    }
+
+    #[cfg(not(esp32c2))]
+    impl Default for SlaveConfig {
+        fn default() -> Self {
+            Self {
+                sda_pullup_enabled: true,
+                scl_pullup_enabled: true,
+                rx_buf_len: 0,
+                tx_buf_len: 0,
+                intr_flags: EnumSet::<InterruptType>::empty(),
+            }
+        }
+    }
+}
+
+/// Transaction statistics of an [`I2cDriver`], available with the `stats` feature
+#[cfg(feature = "stats")]
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq)]
+pub struct I2cStats {
+    /// Number of transactions executed, including failed ones
+    pub transactions: u32,
+    /// Number of transactions which failed because an ACK was missing
+    pub nacks: u32,
+    /// Number of transactions which timed out
+    pub timeouts: u32,
+    /// Number of transactions which failed because of an invalid bus or driver state
+    pub bus_errors: u32,
+}
+
+#[cfg(feature = "stats")]
+impl I2cStats {
+    fn record(&mut self, result: &Result<(), EspError>) {
+        self.transactions = self.transactions.wrapping_add(1);
+
+        if let Err(err) = result {
+            let counter = match err.code() {
+                ESP_FAIL => &mut self.nacks,
+                ESP_ERR_TIMEOUT => &mut self.timeouts,
+                ESP_ERR_INVALID_STATE => &mut self.bus_errors,
+                _ => return,
+            };
+
+            *counter = counter.wrapping_add(1);
+        }
+    }
+}
+
+/// Width of the memory address used by the EEPROM helpers of [`I2cDriver`]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum EepromAddressWidth {
+    /// One address byte, e.g. AT24C01/02
+    OneByte,
+    /// Two address bytes sent MSB first, e.g. AT24C32 and larger
+    TwoBytes,
+}
+
+impl EepromAddressWidth {
+    fn encode(self, mem_addr_be: &[u8; 2]) -> &[u8] {
+        match self {
+            Self::OneByte => &mem_addr_be[1..],
+            Self::TwoBytes => mem_addr_be,
+        }
+    }
+
+    fn capacity(self) -> usize {
+        match self {
+            Self::OneByte => 0x100,
+            Self::TwoBytes => 0x1_0000,
+        }
+    }
+}
+
+// i2c_config_t documentation says that clock speed must be no higher than 1 MHz, on every chip.
+// None of the chips implement the 3.4 MHz high-speed mode. The rate actually reachable below this
+// limit depends on the pull-ups and the bus capacitance rather than on the chip.
+const MAX_BAUDRATE: Hertz = Hertz(1_000_000);
+
+// i2c_filter_enable documents cyc_num as 0..=7 cycles
+const MAX_GLITCH_IGNORE_COUNT: u8 = 7;
+
+// AT24 write cycles take up to 5 ms, while a NACKed poll takes well above 10 us even at 1 MHz
+const EEPROM_WRITE_CYCLE_POLLS: u32 = 1000;
+
+const SMBUS_ALERT_RESPONSE_ADDRESS: u8 = 0x0C;
+
+pub trait I2c: Send {
+    fn port() -> i2c_port_t;
+}
+
+pub struct I2cDriver<'d> {
+    i2c: u8,
+    sda: i32,
+    scl: i32,
+    baudrate: Hertz,
+    drive_strength: Option<DriveStrength>,
+    default_timeout: TickType_t,
+    error_mapper: fn(EspError) -> I2cError,
+    #[cfg(feature = "stats")]
+    stats: I2cStats,
+    _p: PhantomData<&'d mut ()>,
+}
+
+impl<'d> I2cDriver<'d> {
+    pub fn new<I2C: I2c>(
+        _i2c: impl Peripheral<P = I2C> + 'd,
+        sda: impl Peripheral<P = impl InputPin + OutputPin> + 'd,
+        scl: impl Peripheral<P = impl InputPin + OutputPin> + 'd,
+        config: &config::Config,
+    ) -> Result<Self, EspError> {
+        Self::check_config(config)?;
+
+        crate::into_ref!(sda, scl);
+
+        if sda.pin() == scl.pin() {
+            ::log::error!(
+                "I2C SDA and SCL must be different pins, got GPIO{} for both",
+                sda.pin()
+            );
+
+            return Err(EspError::from_infallible::<ESP_ERR_INVALID_ARG>());
+        }
+
+        Self::install(I2C::port(), sda.pin(), scl.pin(), config)?;
+
+        Ok(I2cDriver {
+            i2c: I2C::port() as _,
+            sda: sda.pin(),
+            scl: scl.pin(),
+            baudrate: config.baudrate,
+            drive_strength: config.drive_strength,
+            default_timeout: BLOCK,
+            error_mapper: to_i2c_err,
+            #[cfg(feature = "stats")]
+            stats: Default::default(),
+            _p: PhantomData,
+        })
+    }
+
+    /// Applies a new configuration by re-installing the underlying driver on the same pins
+    ///
+    /// If re-installing fails, the driver is left uninstalled and all subsequent
+    /// transactions fail with `ESP_ERR_INVALID_STATE` until `reconfigure` succeeds.
+    /// [`I2cDriver::baudrate`] then reports the baudrate of the configuration which failed.
+    pub fn reconfigure(&mut self, config: &config::Config) -> Result<(), EspError> {
+        Self::check_config(config)?;
+
+        esp!(unsafe { i2c_driver_delete(self.port()) })?;
+
+        // The previous configuration is gone at this point, whether or not installing succeeds
+        self.baudrate = config.baudrate;
+
+        Self::install(self.port(), self.sda, self.scl, config)?;
+
+        // i2c_param_config leaves the pad drive strength alone, so a strength set by the
+        // previous configuration has to be undone explicitly
+        if self.drive_strength.is_some() && config.drive_strength.is_none() {
+            Self::set_drive_strength(self.sda, self.scl, DriveStrength::I20mA)?;
+        }
+
+        self.drive_strength = config.drive_strength;
+
+        Ok(())
+    }
+
+    /// The SCL frequency requested in the configuration most recently applied with
+    /// [`I2cDriver::new`] or [`I2cDriver::reconfigure`]
+    pub fn baudrate(&self) -> Hertz {
+        self.baudrate
+    }
+
+    /// The timeout used by the `embedded-hal` trait implementations, `BLOCK` by default
+    pub fn default_timeout(&self) -> TickType_t {
+        self.default_timeout
+    }
+
+    /// Sets the timeout used by the `embedded-hal` trait implementations, which otherwise
+    /// block until the transaction completes
+    pub fn set_default_timeout(&mut self, timeout: TickType_t) {
+        self.default_timeout = timeout;
+    }
+
+    /// Replaces the function converting `EspError`s into the [`I2cError`]s returned by the
+    /// `embedded-hal` trait implementations
+    ///
+    /// By default ([`to_i2c_err`]) a NACK maps to `ErrorKind::NoAcknowledge(NoAcknowledgeSource::Unknown)`, a
+    /// timeout or invalid bus state to `ErrorKind::Bus`, and anything else to `ErrorKind::Other`.
+    /// The inherent methods keep returning the `EspError` unchanged.
+    pub fn set_error_mapper(&mut self, mapper: fn(EspError) -> I2cError) {
+        self.error_mapper = mapper;
+    }
+
+    /// Samples SDA and SCL and returns whether both idle high
+    ///
+    /// A `false` result on an idle bus usually means a missing pull-up (for example with the
+    /// internal pull-ups disabled and no external ones fitted) or a device holding the bus.
+    ///
+    /// The pins keep their I2C function: the driver configures them as open-drain with the
+    /// input enabled, so their levels can be read directly between transactions.
+    pub fn check_bus_idle(&mut self) -> Result<bool, EspError> {
+        let sda = unsafe { gpio_get_level(self.sda) } != 0;
+        let scl = unsafe { gpio_get_level(self.scl) } != 0;
+
+        Ok(sda && scl)
+    }
+
+    fn check_config(config: &config::Config) -> Result<(), EspError> {
+        if config.baudrate > MAX_BAUDRATE {
+            ::log::error!(
+                "I2C baudrate {} exceeds the maximum of {} supported by the driver",
+                config.baudrate,
+                MAX_BAUDRATE
+            );
+
+            return Err(EspError::from_infallible::<ESP_ERR_INVALID_ARG>());
+        }
+
+        if let Some(count) = config.glitch_ignore_count {
+            if count > MAX_GLITCH_IGNORE_COUNT {
+                ::log::error!(
+                    "I2C glitch ignore count {} exceeds the maximum of {} cycles",
+                    count,
+                    MAX_GLITCH_IGNORE_COUNT
+                );
+
+                return Err(EspError::from_infallible::<ESP_ERR_INVALID_ARG>());
+            }
+        }
+
+        Ok(())
+    }
+
+    fn set_drive_strength(sda: i32, scl: i32, strength: DriveStrength) -> Result<(), EspError> {
+        esp!(unsafe { gpio_set_drive_capability(sda, strength.into()) })?;
+        esp!(unsafe { gpio_set_drive_capability(scl, strength.into()) })?;
+
+        Ok(())
+    }
+
+    fn install(
+        port: i2c_port_t,
+        sda: i32,
+        scl: i32,
+        config: &config::Config,
+    ) -> Result<(), EspError> {
+        let sys_config = i2c_config_t {
+            mode: i2c_mode_t_I2C_MODE_MASTER,
+            sda_io_num: sda,
+            sda_pullup_en: config.sda_pullup_enabled,
+            scl_io_num: scl,
+            scl_pullup_en: config.scl_pullup_enabled,
+            __bindgen_anon_1: i2c_config_t__bindgen_ty_1 {
+                master: i2c_config_t__bindgen_ty_1__bindgen_ty_1 {
+                    clk_speed: config.baudrate.into(),
+                },
+            },
+            ..Default::default()
+        };
+
+        esp!(unsafe { i2c_param_config(port, &sys_config) })?;
+
+        if let Some(strength) = config.drive_strength {
+            Self::set_drive_strength(sda, scl, strength)?;
+        }
+
+        esp!(unsafe {
+            i2c_driver_install(
+                port,
+                i2c_mode_t_I2C_MODE_MASTER,
+                0, // Not used in master mode
+                0, // Not used in master mode
+                InterruptType::to_native(config.intr_flags) as _,
+            )
+        })?;
+
+        if let Some(timeout) = config.timeout {
+            esp!(unsafe { i2c_set_timeout(port, timeout.0) })?;
+        }
+
+        // i2c_param_config has just enabled the filter with its default of 7 cycles, so it is
+        // only touched when configured. Like i2c_set_timeout, this requires an installed driver
+        match config.glitch_ignore_count {
+            Some(0) => esp!(unsafe { i2c_filter_disable(port) })?,
+            Some(count) => esp!(unsafe { i2c_filter_enable(port, count) })?,
+            None => (),
+        }
+
+        Ok(())
+    }
+
+    pub fn read(
+        &mut self,
+        addr: u8,
+        buffer: &mut [u8],
+        timeout: TickType_t,
+    ) -> Result<(), EspError> {
+        self.read_address(Address::SevenBit(addr), buffer, timeout)
+    }
+
+    pub fn write(&mut self, addr: u8, bytes: &[u8], timeout: TickType_t) -> Result<(), EspError> {
+        self.write_address(Address::SevenBit(addr), bytes, timeout)
+    }
+
+    pub fn write_read(
+        &mut self,
+        addr: u8,
+        bytes: &[u8],
+        buffer: &mut [u8],
+        timeout: TickType_t,
+    ) -> Result<(), EspError> {
+        self.write_read_address(Address::SevenBit(addr), bytes, buffer, timeout)
+    }
+
+    /// Writes the single byte `byte`
+    pub fn write_byte(&mut self, addr: u8, byte: u8, timeout: TickType_t) -> Result<(), EspError> {
+        self.write(addr, &[byte], timeout)
+    }
+
+    /// Reads a single byte
+    pub fn read_byte(&mut self, addr: u8, timeout: TickType_t) -> Result<u8, EspError> {
+        let mut byte = [0];
+
+        self.read(addr, &mut byte, timeout)?;
+
+        Ok(byte[0])
+    }
+
+    /// Same as [`I2cDriver::read`], but reads into a possibly uninitialized buffer, avoiding the
+    /// cost of zeroing large buffers, and returns it as an initialized slice
+    pub fn read_uninit<'b>(
+        &mut self,
+        addr: u8,
+        buffer: &'b mut [core::mem::MaybeUninit<u8>],
+        timeout: TickType_t,
+    ) -> Result<&'b mut [u8], EspError> {
+        {
+            let mut command_link = CommandLink::new()?;
+
+            command_link.master_start_address(Address::SevenBit(addr), true)?;
+
+            if !buffer.is_empty() {
+                command_link.master_read_uninit(buffer, AckType::LastNack)?;
+            }
+
+            command_link.master_stop()?;
+
+            self.cmd_begin(&command_link, timeout)?;
+        }
+
+        // SAFETY: the command link completed successfully, and a read command which is part of a
+        // successful command link has stored all of its `buffer.len()` bytes
+        Ok(unsafe { core::slice::from_raw_parts_mut(buffer.as_mut_ptr().cast(), buffer.len()) })
+    }
+
+    /// Same as [`I2cDriver::write_read`], but terminates the write with a STOP condition and
+    /// performs the read as a separate transaction, instead of using a repeated start
+    ///
+    /// This is only needed for the few devices which do not handle repeated starts.
+    /// `timeout` applies to each of the two transactions separately.
+    pub fn write_stop_read(
+        &mut self,
+        addr: u8,
+        bytes: &[u8],
+        buffer: &mut [u8],
+        timeout: TickType_t,
+    ) -> Result<(), EspError> {
+        self.write(addr, bytes, timeout)?;
+        self.read(addr, buffer, timeout)
+    }
+
+    /// Reads `buffer.len()` bytes repeatedly until two consecutive reads return the same data
+    ///
+    /// This is a cheap integrity check for devices without a CRC of their own on noisy buses,
+    /// at the cost of at least one extra read per call. If the reads still disagree after
+    /// `retries` additional attempts, `ESP_ERR_INVALID_RESPONSE` is returned.
+    /// Reads are compared by digest, so no second buffer is needed.
+    pub fn read_verified(
+        &mut self,
+        addr: u8,
+        buffer: &mut [u8],
+        timeout: TickType_t,
+        retries: u32,
+    ) -> Result<(), EspError> {
+        self.read(addr, buffer, timeout)?;
+
+        let mut previous = digest(buffer);
+
+        for _ in 0..=retries {
+            self.read(addr, buffer, timeout)?;
+
+            let current = digest(buffer);
+            if current == previous {
+                return Ok(());
+            }
+
+            previous = current;
+        }
+
+        Err(EspError::from_infallible::<ESP_ERR_INVALID_RESPONSE>())
+    }
+
+    /// Writes `bytes` in chunks of up to `chunk_size` bytes, each as a separate transaction
+    /// terminated by a STOP condition
+    ///
+    /// This gives up the atomicity of the overall transfer, so it is only suitable for devices
+    /// which accept the data split over several transactions (e.g. display RAM or EEPROM pages).
+    pub fn write_chunked(
+        &mut self,
+        addr: u8,
+        bytes: &[u8],
+        chunk_size: usize,
+        timeout: TickType_t,
+    ) -> Result<(), EspError> {
+        self.write_chunks(addr, bytes, chunk_size, false, timeout)
+    }
+
+    /// Same as [`I2cDriver::write_chunked`], but also yields to other ready tasks of the same
+    /// priority between chunks
+    pub fn write_with_yield(
+        &mut self,
+        addr: u8,
+        bytes: &[u8],
+        chunk_size: usize,
+        timeout: TickType_t,
+    ) -> Result<(), EspError> {
+        self.write_chunks(addr, bytes, chunk_size, true, timeout)
+    }
+
+    fn write_chunks(
+        &mut self,
+        addr: u8,
+        bytes: &[u8],
+        chunk_size: usize,
+        yield_between: bool,
+        timeout: TickType_t,
+    ) -> Result<(), EspError> {
+        if chunk_size == 0 {
+            return Err(EspError::from_infallible::<ESP_ERR_INVALID_ARG>());
+        }
+
+        for (index, chunk) in bytes.chunks(chunk_size).enumerate() {
+            if yield_between && index > 0 {
+                FreeRtos::delay_ms(0);
+            }
+
+            self.write(addr, chunk, timeout)?;
+        }
+
+        Ok(())
+    }
+
+    /// Writes `data` to an AT24-style EEPROM starting at memory address `mem_addr`
+    ///
+    /// The data is split at `page_size` boundaries, since a single write cannot cross a page.
+    /// Every page is written as its own transaction prefixed with the memory address, after
+    /// which the EEPROM is polled with address-only writes until it has finished its write cycle.
+    pub fn eeprom_write(
+        &mut self,
+        addr: u8,
+        mem_addr: u16,
+        data: &[u8],
+        page_size: usize,
+        width: EepromAddressWidth,
+        timeout: TickType_t,
+    ) -> Result<(), EspError> {
+        if page_size == 0 || mem_addr as usize + data.len() > width.capacity() {
+            return Err(EspError::from_infallible::<ESP_ERR_INVALID_ARG>());
+        }
+
+        let mut mem_addr = mem_addr as usize;
+        let mut data = data;
+
+        while !data.is_empty() {
+            let len = (page_size - mem_addr % page_size).min(data.len());
+            let (page, rest) = data.split_at(len);
+
+            let mem_addr_bytes = (mem_addr as u16).to_be_bytes();
+
+            self.write_vectored(addr, &[width.encode(&mem_addr_bytes), page], timeout)?;
+
+            self.wait_ready(addr, EEPROM_WRITE_CYCLE_POLLS, timeout)?;
+
+            mem_addr += len;
+            data = rest;
+        }
+
+        Ok(())
+    }
+
+    /// Reads `buffer.len()` bytes from an AT24-style EEPROM starting at memory address `mem_addr`
+    pub fn eeprom_read(
+        &mut self,
+        addr: u8,
+        mem_addr: u16,
+        buffer: &mut [u8],
+        width: EepromAddressWidth,
+        timeout: TickType_t,
+    ) -> Result<(), EspError> {
+        if mem_addr as usize + buffer.len() > width.capacity() {
+            return Err(EspError::from_infallible::<ESP_ERR_INVALID_ARG>());
+        }
+
+        let mem_addr_bytes = mem_addr.to_be_bytes();
+
+        self.write_read(addr, width.encode(&mem_addr_bytes), buffer, timeout)
+    }
+
+    /// Polls the device with address-only writes until it acknowledges, which is how EEPROMs and
+    /// similar devices signal the end of an internal write cycle
+    ///
+    /// Returns `ESP_ERR_TIMEOUT` if the device is still busy after `max_attempts` polls.
+    /// Errors other than a missing ACK are returned immediately.
+    pub fn wait_ready(
+        &mut self,
+        addr: u8,
+        max_attempts: u32,
+        timeout: TickType_t,
+    ) -> Result<(), EspError> {
+        for _ in 0..max_attempts {
+            match self.quick_command(addr, false, timeout) {
+                Err(err) if err.code() == ESP_FAIL => continue,
+                other => return other,
+            }
+        }
+
+        Err(EspError::from_infallible::<ESP_ERR_TIMEOUT>())
+    }
+
+    /// Writes `bytes` to the general call address (0x00), i.e. to all devices on the bus at once
+    ///
+    /// The transaction still requires an ACK, so it fails with a NACK error if no device responds
+    /// to general calls. Note that the slave driver of this crate cannot be configured to answer
+    /// general calls.
+    pub fn general_call(&mut self, bytes: &[u8], timeout: TickType_t) -> Result<(), EspError> {
+        self.write(0x00, bytes, timeout)
+    }
+
+    /// Issues an SMBus "Quick Command", i.e. only the address with the R/W bit set to `read`
+    /// and no data
+    ///
+    /// The address byte must be acknowledged, so with `read = false` this also doubles as a
+    /// presence check, as used by [`I2cDriver::wait_ready`].
+    ///
+    /// `read = true` is only safe for devices which implement the SMBus Quick Command: any other
+    /// device ACKs and then starts driving its first data bit, and if that bit is 0 it holds SDA
+    /// low, so the STOP condition can fail and the bus can hang. Never probe with `read = true`.
+    pub fn quick_command(
+        &mut self,
+        addr: u8,
+        read: bool,
+        timeout: TickType_t,
+    ) -> Result<(), EspError> {
+        let mut command_link = CommandLink::new()?;
+
+        command_link.master_start_address(Address::SevenBit(addr), read)?;
+        command_link.master_stop()?;
+
+        self.cmd_begin(&command_link, timeout)
+    }
+
+    /// Writes the register address `reg` and reads `buffer.len()` bytes back using a repeated start
+    pub fn read_register(
+        &mut self,
+        addr: u8,
+        reg: u8,
+        buffer: &mut [u8],
+        timeout: TickType_t,
+    ) -> Result<(), EspError> {
+        self.write_read(addr, &[reg], buffer, timeout)
+    }
+
+    /// Same as [`I2cDriver::read_register`], but for devices with 16-bit register addresses,
+    /// which are sent MSB first
+    pub fn read_register16(
+        &mut self,
+        addr: u8,
+        reg: u16,
+        buffer: &mut [u8],
+        timeout: TickType_t,
+    ) -> Result<(), EspError> {
+        self.write_read(addr, &reg.to_be_bytes(), buffer, timeout)
+    }
+
+    /// Writes the register address `reg` immediately followed by `bytes` in a single transaction
+    pub fn write_register(
+        &mut self,
+        addr: u8,
+        reg: u8,
+        bytes: &[u8],
+        timeout: TickType_t,
+    ) -> Result<(), EspError> {
+        self.write_vectored(addr, &[&[reg], bytes], timeout)
+    }
+
+    /// Reads `count` consecutive registers starting at `start` into `out` with a single
+    /// transaction, relying on the device to auto-increment its register pointer
+    ///
+    /// Returns `ESP_ERR_INVALID_SIZE` if `out` is shorter than `count`, and does nothing if
+    /// `count` is 0.
+    pub fn dump_registers(
+        &mut self,
+        addr: u8,
+        start: u8,
+        count: u8,
+        out: &mut [u8],
+        timeout: TickType_t,
+    ) -> Result<(), EspError> {
+        // An empty read would still address the device for reading, which can hang the bus
+        // (see `quick_command`)
+        if count == 0 {
+            return Ok(());
+        }
+
+        let out = out
+            .get_mut(..count as usize)
+            .ok_or(EspError::from_infallible::<ESP_ERR_INVALID_SIZE>())?;
+
+        self.read_register(addr, start, out, timeout)
+    }
+
+    /// Same as [`I2cDriver::dump_registers`], but reads every register with its own transaction,
+    /// for devices which do not auto-increment their register pointer
+    pub fn dump_registers_individually(
+        &mut self,
+        addr: u8,
+        start: u8,
+        count: u8,
+        out: &mut [u8],
+        timeout: TickType_t,
+    ) -> Result<(), EspError> {
+        let out = out
+            .get_mut(..count as usize)
+            .ok_or(EspError::from_infallible::<ESP_ERR_INVALID_SIZE>())?;
+
+        for (offset, value) in out.iter_mut().enumerate() {
+            let reg = start.wrapping_add(offset as u8);
+
+            self.read_register(addr, reg, core::slice::from_mut(value), timeout)?;
+        }
+
+        Ok(())
+    }
+
+    /// Reads the one-byte register `reg`, applies `f` to its value and writes the result back
+    ///
+    /// The read and the write are two separate transactions.
+    pub fn modify_register(
+        &mut self,
+        addr: u8,
+        reg: u8,
+        f: impl FnOnce(u8) -> u8,
+        timeout: TickType_t,
+    ) -> Result<(), EspError> {
+        let mut value = [0];
+
+        self.read_register(addr, reg, &mut value, timeout)?;
+        self.write_register(addr, reg, &[f(value[0])], timeout)
+    }
+
+    /// Sets the bits of the one-byte register `reg` selected by `mask` to the matching bits of
+    /// `value`, leaving all other bits untouched
+    pub fn update_register_bits(
+        &mut self,
+        addr: u8,
+        reg: u8,
+        mask: u8,
+        value: u8,
+        timeout: TickType_t,
+    ) -> Result<(), EspError> {
+        self.modify_register(addr, reg, |old| (old & !mask) | (value & mask), timeout)
+    }
+
+    /// Writes all `slices` back to back as a single transaction, without copying them into
+    /// an intermediate buffer
+    pub fn write_vectored(
+        &mut self,
+        addr: u8,
+        slices: &[&[u8]],
+        timeout: TickType_t,
+    ) -> Result<(), EspError> {
+        let mut command_link = CommandLink::new()?;
+
+        command_link.master_start_address(Address::SevenBit(addr), false)?;
+
+        for bytes in slices {
+            if !bytes.is_empty() {
+                command_link.master_write(bytes, true)?;
             }
         }
+
+        command_link.master_stop()?;
+
+        self.cmd_begin(&command_link, timeout)
     }
 
-    /// I2C Slave configuration
-    #[cfg(not(esp32c2))]
-    #[derive(Debug, Clone)]
-    pub struct SlaveConfig {
-        pub sda_pullup_enabled: bool,
-        pub scl_pullup_enabled: bool,
-        pub rx_buf_len: usize,
-        pub tx_buf_len: usize,
-        pub intr_flags: EnumSet<InterruptType>,
-    }
+    /// Writes `bytes` followed by the SMBus Packet Error Code computed over the address and `bytes`
+    pub fn write_pec(
+        &mut self,
+        addr: u8,
+        bytes: &[u8],
+        timeout: TickType_t,
+    ) -> Result<(), EspError> {
+        let pec = smbus_pec(
+            smbus_pec(0, &[(addr << 1) | (i2c_rw_t_I2C_MASTER_WRITE as u8)]),
+            bytes,
+        );
+
+        let mut command_link = CommandLink::new()?;
+
+        command_link.master_start_address(Address::SevenBit(addr), false)?;
+
+        if !bytes.is_empty() {
+            command_link.master_write(bytes, true)?;
+        }
+
+        command_link.master_write_byte(pec, true)?;
+        command_link.master_stop()?;
+
+        self.cmd_begin(&command_link, timeout)
+    }
+
+    /// Reads `buffer.len()` bytes followed by the SMBus Packet Error Code and verifies it
+    ///
+    /// Returns `ESP_ERR_INVALID_CRC` if the received PEC does not match the data.
+    pub fn read_pec(
+        &mut self,
+        addr: u8,
+        buffer: &mut [u8],
+        timeout: TickType_t,
+    ) -> Result<(), EspError> {
+        self.write_read_pec(addr, &[], buffer, timeout)
+    }
+
+    /// Writes `bytes` (if any) and reads `buffer.len()` bytes followed by the SMBus Packet Error Code
+    /// using a repeated start, as done by the SMBus "Read Byte/Word/Block" protocols
+    ///
+    /// Returns `ESP_ERR_INVALID_CRC` if the received PEC does not match.
+    pub fn write_read_pec(
+        &mut self,
+        addr: u8,
+        bytes: &[u8],
+        buffer: &mut [u8],
+        timeout: TickType_t,
+    ) -> Result<(), EspError> {
+        let mut pec = [0_u8];
+
+        {
+            let mut command_link = CommandLink::new()?;
+
+            if !bytes.is_empty() {
+                command_link.master_start_address(Address::SevenBit(addr), false)?;
+                command_link.master_write(bytes, true)?;
+            }
+
+            command_link.master_start_address(Address::SevenBit(addr), true)?;
 
-    #[cfg(not(esp32c2))]
-    impl SlaveConfig {
-        pub fn new() -> Self {
-            Default::default()
-        }
+            if !buffer.is_empty() {
+                command_link.master_read(buffer, AckType::Ack)?;
+            }
 
-        #[must_use]
-        pub fn sda_enable_pullup(mut self, enable: bool) -> Self {
-            self.sda_pullup_enabled = enable;
-            self
-        }
+            command_link.master_read(&mut pec, AckType::LastNack)?;
+            command_link.master_stop()?;
 
-        #[must_use]
-        pub fn scl_enable_pullup(mut self, enable: bool) -> Self {
-            self.scl_pullup_enabled = enable;
-            self
+            self.cmd_begin(&command_link, timeout)?;
         }
 
-        #[must_use]
-        pub fn rx_buffer_length(mut self, len: usize) -> Self {
-            self.rx_buf_len = len;
-            self
-        }
+        let mut crc = 0;
 
-        #[must_use]
-        pub fn tx_buffer_length(mut self, len: usize) -> Self {
-            self.tx_buf_len = len;
-            self
+        if !bytes.is_empty() {
+            crc = smbus_pec(crc, &[(addr << 1) | (i2c_rw_t_I2C_MASTER_WRITE as u8)]);
+            crc = smbus_pec(crc, bytes);
         }
 
-        #[must_use]
-        pub fn intr_flags(mut self, flags: EnumSet<InterruptType>) -> Self {
-            self.intr_flags = flags;
-            self
+        crc = smbus_pec(crc, &[(addr << 1) | (i2c_rw_t_I2C_MASTER_READ as u8)]);
+        crc = smbus_pec(crc, buffer);
+
+        if crc == pec[0] {
+            Ok(())
+        } else {
+            Err(EspError::from_infallible::<ESP_ERR_INVALID_CRC>())
         }
     }
 
-    #[cfg(not(esp32c2))]
-    impl Default for SlaveConfig {
-        fn default() -> Self {
-            Self {
-                sda_pullup_enabled: true,
-                scl_pullup_enabled: true,
-                rx_buf_len: 0,
-                tx_buf_len: 0,
-                intr_flags: EnumSet::<InterruptType>::empty(),
-            }
+    /// Reads from the SMBus Alert Response Address (0x0C) to find out which device asserted
+    /// SMBALERT#, returning its 7-bit address or `None` if no device responds
+    ///
+    /// If several devices are alerting, the one with the lowest address wins arbitration and
+    /// releases the alert line; call this again until `None` is returned. SMBALERT# itself is
+    /// not part of the I2C bus, so watch it with a GPIO interrupt and call this from there.
+    pub fn smbus_alert_response(&mut self, timeout: TickType_t) -> Result<Option<u8>, EspError> {
+        let mut address = [0_u8];
+
+        match self.read(SMBUS_ALERT_RESPONSE_ADDRESS, &mut address, timeout) {
+            Ok(()) => Ok(Some(address[0] >> 1)),
+            Err(err) if err.code() == ESP_FAIL => Ok(None),
+            Err(err) => Err(err),
         }
     }
-}
-
-pub trait I2c: Send {
-    fn port() -> i2c_port_t;
-}
 
-pub struct I2cDriver<'d> {
-    i2c: u8,
-    _p: PhantomData<&'d mut ()>,
-}
+    pub fn transaction(
+        &mut self,
+        address: u8,
+        operations: &mut [Operation<'_>],
+        timeout: TickType_t,
+    ) -> Result<(), EspError> {
+        self.transaction_address(Address::SevenBit(address), operations, timeout)
+    }
 
-impl<'d> I2cDriver<'d> {
-    pub fn new<I2C: I2c>(
-        _i2c: impl Peripheral<P = I2C> + 'd,
-        sda: impl Peripheral<P = impl InputPin + OutputPin> + 'd,
-        scl: impl Peripheral<P = impl InputPin + OutputPin> + 'd,
-        config: &config::Config,
-    ) -> Result<Self, EspError> {
-        // i2c_config_t documentation says that clock speed must be no higher than 1 MHz
-        if config.baudrate > 1.MHz().into() {
-            return Err(EspError::from_infallible::<ESP_ERR_INVALID_ARG>());
-        }
+    pub fn read_10bit(
+        &mut self,
+        addr: u16,
+        buffer: &mut [u8],
+        timeout: TickType_t,
+    ) -> Result<(), EspError> {
+        self.read_address(Address::TenBit(addr), buffer, timeout)
+    }
 
-        crate::into_ref!(sda, scl);
+    pub fn write_10bit(
+        &mut self,
+        addr: u16,
+        bytes: &[u8],
+        timeout: TickType_t,
+    ) -> Result<(), EspError> {
+        self.write_address(Address::TenBit(addr), bytes, timeout)
+    }
 
-        let sys_config = i2c_config_t {
-            mode: i2c_mode_t_I2C_MODE_MASTER,
-            sda_io_num: sda.pin(),
-            sda_pullup_en: config.sda_pullup_enabled,
-            scl_io_num: scl.pin(),
-            scl_pullup_en: config.scl_pullup_enabled,
-            __bindgen_anon_1: i2c_config_t__bindgen_ty_1 {
-                master: i2c_config_t__bindgen_ty_1__bindgen_ty_1 {
-                    clk_speed: config.baudrate.into(),
-                },
-            },
-            ..Default::default()
-        };
+    pub fn write_read_10bit(
+        &mut self,
+        addr: u16,
+        bytes: &[u8],
+        buffer: &mut [u8],
+        timeout: TickType_t,
+    ) -> Result<(), EspError> {
+        self.write_read_address(Address::TenBit(addr), bytes, buffer, timeout)
+    }
 
-        esp!(unsafe { i2c_param_config(I2C::port(), &sys_config) })?;
+    pub fn transaction_10bit(
+        &mut self,
+        address: u16,
+        operations: &mut [Operation<'_>],
+        timeout: TickType_t,
+    ) -> Result<(), EspError> {
+        self.transaction_address(Address::TenBit(address), operations, timeout)
+    }
 
-        esp!(unsafe {
-            i2c_driver_install(
-                I2C::port(),
-                i2c_mode_t_I2C_MODE_MASTER,
-                0, // Not used in master mode
-                0, // Not used in master mode
-                InterruptType::to_native(config.intr_flags) as _,
-            )
-        })?;
+    /// Executes an arbitrary sequence of start/stop conditions, writes and reads as one command link
+    ///
+    /// Nothing is added implicitly: the address byte(s) must be written explicitly after every
+    /// start, and the sequence should normally end with [`RawI2cCommand::Stop`].
+    pub fn execute(
+        &mut self,
+        commands: &mut [RawI2cCommand<'_>],
+        timeout: TickType_t,
+    ) -> Result<(), EspError> {
+        let mut command_link = CommandLink::new()?;
 
-        if let Some(timeout) = config.timeout {
-            esp!(unsafe { i2c_set_timeout(I2C::port(), timeout.0) })?;
+        for command in commands.iter_mut() {
+            match command {
+                RawI2cCommand::Start => command_link.master_start()?,
+                RawI2cCommand::Write(bytes, ack_check) => {
+                    if !bytes.is_empty() {
+                        command_link.master_write(bytes, *ack_check)?;
+                    }
+                }
+                RawI2cCommand::Read(buf, ack) => {
+                    if !buf.is_empty() {
+                        command_link.master_read(buf, *ack)?;
+                    }
+                }
+                RawI2cCommand::Stop => command_link.master_stop()?,
+            }
         }
 
-        Ok(I2cDriver {
-            i2c: I2C::port() as _,
-            _p: PhantomData,
-        })
+        self.cmd_begin(&command_link, timeout)
     }
 
-    pub fn read(
+    fn read_address(
         &mut self,
-        addr: u8,
+        address: Address,
         buffer: &mut [u8],
         timeout: TickType_t,
     ) -> Result<(), EspError> {
         let mut command_link = CommandLink::new()?;
 
-        command_link.master_start()?;
-        command_link.master_write_byte((addr << 1) | (i2c_rw_t_I2C_MASTER_READ as u8), true)?;
+        command_link.master_start_address(address, true)?;
 
         if !buffer.is_empty() {
             command_link.master_read(buffer, AckType::LastNack)?;
@@ -267,11 +1148,15 @@ impl<'d> I2cDriver<'d> {
         self.cmd_begin(&command_link, timeout)
     }
 
-    pub fn write(&mut self, addr: u8, bytes: &[u8], timeout: TickType_t) -> Result<(), EspError> {
+    fn write_address(
+        &mut self,
+        address: Address,
+        bytes: &[u8],
+        timeout: TickType_t,
+    ) -> Result<(), EspError> {
         let mut command_link = CommandLink::new()?;
 
-        command_link.master_start()?;
-        command_link.master_write_byte((addr << 1) | (i2c_rw_t_I2C_MASTER_WRITE as u8), true)?;
+        command_link.master_start_address(address, false)?;
 
         if !bytes.is_empty() {
             command_link.master_write(bytes, true)?;
@@ -282,24 +1167,22 @@ impl<'d> I2cDriver<'d> {
         self.cmd_begin(&command_link, timeout)
     }
 
-    pub fn write_read(
+    fn write_read_address(
         &mut self,
-        addr: u8,
+        address: Address,
         bytes: &[u8],
         buffer: &mut [u8],
         timeout: TickType_t,
     ) -> Result<(), EspError> {
         let mut command_link = CommandLink::new()?;
 
-        command_link.master_start()?;
-        command_link.master_write_byte((addr << 1) | (i2c_rw_t_I2C_MASTER_WRITE as u8), true)?;
+        command_link.master_start_address(address, false)?;
 
         if !bytes.is_empty() {
             command_link.master_write(bytes, true)?;
         }
 
-        command_link.master_start()?;
-        command_link.master_write_byte((addr << 1) | (i2c_rw_t_I2C_MASTER_READ as u8), true)?;
+        command_link.master_start_address(address, true)?;
 
         if !buffer.is_empty() {
             command_link.master_read(buffer, AckType::LastNack)?;
@@ -310,12 +1193,16 @@ impl<'d> I2cDriver<'d> {
         self.cmd_begin(&command_link, timeout)
     }
 
-    pub fn transaction(
+    fn transaction_address(
         &mut self,
-        address: u8,
+        address: Address,
         operations: &mut [Operation<'_>],
         timeout: TickType_t,
     ) -> Result<(), EspError> {
+        if operations.is_empty() {
+            return Ok(());
+        }
+
         let mut command_link = CommandLink::new()?;
 
         let last_op_index = operations.len() - 1;
@@ -325,11 +1212,7 @@ impl<'d> I2cDriver<'d> {
             match operation {
                 Operation::Read(buf) => {
                     if Some(true) != prev_was_read {
-                        command_link.master_start()?;
-                        command_link.master_write_byte(
-                            (address << 1) | (i2c_rw_t_I2C_MASTER_READ as u8),
-                            true,
-                        )?;
+                        command_link.master_start_address(address, true)?;
                     }
                     prev_was_read = Some(true);
 
@@ -345,11 +1228,7 @@ impl<'d> I2cDriver<'d> {
                 }
                 Operation::Write(buf) => {
                     if Some(false) != prev_was_read {
-                        command_link.master_start()?;
-                        command_link.master_write_byte(
-                            (address << 1) | (i2c_rw_t_I2C_MASTER_WRITE as u8),
-                            true,
-                        )?;
+                        command_link.master_start_address(address, false)?;
                     }
                     prev_was_read = Some(false);
 
@@ -370,7 +1249,18 @@ impl<'d> I2cDriver<'d> {
         command_link: &CommandLink,
         timeout: TickType_t,
     ) -> Result<(), EspError> {
-        esp!(unsafe { i2c_master_cmd_begin(self.port(), command_link.0, timeout) })
+        let result = esp!(unsafe { i2c_master_cmd_begin(self.port(), command_link.0, timeout) });
+
+        #[cfg(feature = "stats")]
+        self.stats.record(&result);
+
+        result
+    }
+
+    /// A snapshot of the transaction statistics collected since the driver was created
+    #[cfg(feature = "stats")]
+    pub fn stats(&self) -> I2cStats {
+        self.stats
     }
 
     pub fn port(&self) -> i2c_port_t {
@@ -380,17 +1270,37 @@ impl<'d> I2cDriver<'d> {
 
 impl<'d> Drop for I2cDriver<'d> {
     fn drop(&mut self) {
-        esp!(unsafe { i2c_driver_delete(self.port()) }).unwrap();
+        if let Err(err) = esp!(unsafe { i2c_driver_delete(self.port()) }) {
+            ::log::error!(
+                "Failed to delete the I2C driver on port {}: {}",
+                self.port(),
+                err
+            );
+        }
     }
 }
 
 unsafe impl<'d> Send for I2cDriver<'d> {}
 
+impl<'d> core::fmt::Debug for I2cDriver<'d> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("I2cDriver")
+            .field("port", &self.i2c)
+            .field("sda", &self.sda)
+            .field("scl", &self.scl)
+            .field("baudrate", &self.baudrate)
+            .field("default_timeout", &self.default_timeout)
+            .finish()
+    }
+}
+
 impl<'d> embedded_hal_0_2::blocking::i2c::Read for I2cDriver<'d> {
     type Error = I2cError;
 
     fn read(&mut self, addr: u8, buffer: &mut [u8]) -> Result<(), Self::Error> {
-        I2cDriver::read(self, addr, buffer, BLOCK).map_err(to_i2c_err)
+        let timeout = self.default_timeout;
+
+        I2cDriver::read(self, addr, buffer, timeout).map_err(self.error_mapper)
     }
 }
 
@@ -398,7 +1308,9 @@ impl<'d> embedded_hal_0_2::blocking::i2c::Write for I2cDriver<'d> {
     type Error = I2cError;
 
     fn write(&mut self, addr: u8, bytes: &[u8]) -> Result<(), Self::Error> {
-        I2cDriver::write(self, addr, bytes, BLOCK).map_err(to_i2c_err)
+        let timeout = self.default_timeout;
+
+        I2cDriver::write(self, addr, bytes, timeout).map_err(self.error_mapper)
     }
 }
 
@@ -406,7 +1318,9 @@ impl<'d> embedded_hal_0_2::blocking::i2c::WriteRead for I2cDriver<'d> {
     type Error = I2cError;
 
     fn write_read(&mut self, addr: u8, bytes: &[u8], buffer: &mut [u8]) -> Result<(), Self::Error> {
-        I2cDriver::write_read(self, addr, bytes, buffer, BLOCK).map_err(to_i2c_err)
+        let timeout = self.default_timeout;
+
+        I2cDriver::write_read(self, addr, bytes, buffer, timeout).map_err(self.error_mapper)
     }
 }
 
@@ -416,15 +1330,21 @@ impl<'d> embedded_hal::i2c::ErrorType for I2cDriver<'d> {
 
 impl<'d> embedded_hal::i2c::I2c<embedded_hal::i2c::SevenBitAddress> for I2cDriver<'d> {
     fn read(&mut self, addr: u8, buffer: &mut [u8]) -> Result<(), Self::Error> {
-        I2cDriver::read(self, addr, buffer, BLOCK).map_err(to_i2c_err)
+        let timeout = self.default_timeout;
+
+        I2cDriver::read(self, addr, buffer, timeout).map_err(self.error_mapper)
     }
 
     fn write(&mut self, addr: u8, bytes: &[u8]) -> Result<(), Self::Error> {
-        I2cDriver::write(self, addr, bytes, BLOCK).map_err(to_i2c_err)
+        let timeout = self.default_timeout;
+
+        I2cDriver::write(self, addr, bytes, timeout).map_err(self.error_mapper)
     }
 
     fn write_read(&mut self, addr: u8, bytes: &[u8], buffer: &mut [u8]) -> Result<(), Self::Error> {
-        I2cDriver::write_read(self, addr, bytes, buffer, BLOCK).map_err(to_i2c_err)
+        let timeout = self.default_timeout;
+
+        I2cDriver::write_read(self, addr, bytes, buffer, timeout).map_err(self.error_mapper)
     }
 
     fn transaction(
@@ -432,15 +1352,78 @@ impl<'d> embedded_hal::i2c::I2c<embedded_hal::i2c::SevenBitAddress> for I2cDrive
         address: u8,
         operations: &mut [embedded_hal::i2c::Operation<'_>],
     ) -> Result<(), Self::Error> {
-        I2cDriver::transaction(self, address, operations, BLOCK).map_err(to_i2c_err)
+        let timeout = self.default_timeout;
+
+        I2cDriver::transaction(self, address, operations, timeout).map_err(self.error_mapper)
+    }
+}
+
+impl<'d> embedded_hal::i2c::I2c<embedded_hal::i2c::TenBitAddress> for I2cDriver<'d> {
+    fn read(&mut self, addr: u16, buffer: &mut [u8]) -> Result<(), Self::Error> {
+        let timeout = self.default_timeout;
+
+        I2cDriver::read_10bit(self, addr, buffer, timeout).map_err(self.error_mapper)
+    }
+
+    fn write(&mut self, addr: u16, bytes: &[u8]) -> Result<(), Self::Error> {
+        let timeout = self.default_timeout;
+
+        I2cDriver::write_10bit(self, addr, bytes, timeout).map_err(self.error_mapper)
+    }
+
+    fn write_read(
+        &mut self,
+        addr: u16,
+        bytes: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<(), Self::Error> {
+        let timeout = self.default_timeout;
+
+        I2cDriver::write_read_10bit(self, addr, bytes, buffer, timeout).map_err(self.error_mapper)
+    }
+
+    fn transaction(
+        &mut self,
+        address: u16,
+        operations: &mut [embedded_hal::i2c::Operation<'_>],
+    ) -> Result<(), Self::Error> {
+        let timeout = self.default_timeout;
+
+        I2cDriver::transaction_10bit(self, address, operations, timeout).map_err(self.error_mapper)
+    }
+}
+
+/// 32-bit FNV-1a hash, used to compare consecutive reads without keeping a copy
+fn digest(bytes: &[u8]) -> u32 {
+    bytes.iter().fold(0x811c_9dc5, |hash, byte| {
+        (hash ^ *byte as u32).wrapping_mul(0x0100_0193)
+    })
+}
+
+/// CRC-8 with polynomial x^8 + x^2 + x + 1 (0x07), as used for the SMBus Packet Error Code
+fn smbus_pec(mut crc: u8, bytes: &[u8]) -> u8 {
+    for byte in bytes {
+        crc ^= byte;
+
+        for _ in 0..8 {
+            crc = if crc & 0x80 != 0 {
+                (crc << 1) ^ 0x07
+            } else {
+                crc << 1
+            };
+        }
     }
+
+    crc
 }
 
-fn to_i2c_err(err: EspError) -> I2cError {
-    if err.code() == ESP_FAIL {
-        I2cError::new(ErrorKind::NoAcknowledge(NoAcknowledgeSource::Unknown), err)
-    } else {
-        I2cError::other(err)
+/// The default error mapping of [`I2cDriver`], for custom mappers to fall back to
+pub fn to_i2c_err(err: EspError) -> I2cError {
+    match err.code() {
+        ESP_FAIL => I2cError::new(ErrorKind::NoAcknowledge(NoAcknowledgeSource::Unknown), err),
+        // The driver reports a bus which stays busy (or a hung FSM) as a timeout / invalid state
+        ESP_ERR_TIMEOUT | ESP_ERR_INVALID_STATE => I2cError::new(ErrorKind::Bus, err),
+        _ => I2cError::other(err),
     }
 }
 
@@ -453,17 +1436,63 @@ pub struct I2cSlaveDriver<'d> {
 #[cfg(not(esp32c2))]
 unsafe impl<'d> Send for I2cSlaveDriver<'d> {}
 
+#[cfg(not(esp32c2))]
+impl<'d> core::fmt::Debug for I2cSlaveDriver<'d> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("I2cSlaveDriver")
+            .field("port", &self.i2c)
+            .finish()
+    }
+}
+
 #[cfg(not(esp32c2))]
 impl<'d> I2cSlaveDriver<'d> {
     pub fn new<I2C: I2c>(
-        _i2c: impl Peripheral<P = I2C> + 'd,
+        i2c: impl Peripheral<P = I2C> + 'd,
         sda: impl Peripheral<P = impl InputPin + OutputPin> + 'd,
         scl: impl Peripheral<P = impl InputPin + OutputPin> + 'd,
         slave_addr: u8,
         config: &config::SlaveConfig,
     ) -> Result<Self, EspError> {
+        Self::new_address(i2c, sda, scl, Address::SevenBit(slave_addr), config)
+    }
+
+    /// Same as [`I2cSlaveDriver::new`], but answers to the 10-bit address `slave_addr` (up to 0x3FF)
+    pub fn new_10bit<I2C: I2c>(
+        i2c: impl Peripheral<P = I2C> + 'd,
+        sda: impl Peripheral<P = impl InputPin + OutputPin> + 'd,
+        scl: impl Peripheral<P = impl InputPin + OutputPin> + 'd,
+        slave_addr: u16,
+        config: &config::SlaveConfig,
+    ) -> Result<Self, EspError> {
+        Self::new_address(i2c, sda, scl, Address::TenBit(slave_addr), config)
+    }
+
+    fn new_address<I2C: I2c>(
+        _i2c: impl Peripheral<P = I2C> + 'd,
+        sda: impl Peripheral<P = impl InputPin + OutputPin> + 'd,
+        scl: impl Peripheral<P = impl InputPin + OutputPin> + 'd,
+        address: Address,
+        config: &config::SlaveConfig,
+    ) -> Result<Self, EspError> {
+        address.check()?;
+
         crate::into_ref!(sda, scl);
 
+        if sda.pin() == scl.pin() {
+            ::log::error!(
+                "I2C SDA and SCL must be different pins, got GPIO{} for both",
+                sda.pin()
+            );
+
+            return Err(EspError::from_infallible::<ESP_ERR_INVALID_ARG>());
+        }
+
+        let (slave_addr, addr_10bit_en) = match address {
+            Address::SevenBit(addr) => (addr as u16, 0),
+            Address::TenBit(addr) => (addr, 1),
+        };
+
         let sys_config = i2c_config_t {
             mode: i2c_mode_t_I2C_MODE_SLAVE,
             sda_io_num: sda.pin(),
@@ -472,8 +1501,8 @@ impl<'d> I2cSlaveDriver<'d> {
             scl_pullup_en: config.scl_pullup_enabled,
             __bindgen_anon_1: i2c_config_t__bindgen_ty_1 {
                 slave: i2c_config_t__bindgen_ty_1__bindgen_ty_2 {
-                    slave_addr: slave_addr as u16,
-                    addr_10bit_en: 0, // For now; to become configurable with embedded-hal V1.0
+                    slave_addr,
+                    addr_10bit_en,
                     maximum_speed: 0,
                 },
             },
@@ -503,10 +1532,26 @@ impl<'d> I2cSlaveDriver<'d> {
             i2c_slave_read_buffer(self.port(), buffer.as_mut_ptr(), buffer.len(), timeout)
         };
 
+        // Zero means no data arrived before `timeout` expired, while a negative value
+        // is `ESP_FAIL` signalling invalid parameters
         if n > 0 {
             Ok(n as usize)
-        } else {
+        } else if n == 0 {
             Err(EspError::from_infallible::<ESP_ERR_TIMEOUT>())
+        } else {
+            Err(EspError::from_infallible::<ESP_FAIL>())
+        }
+    }
+
+    /// Non-blocking variant of [`I2cSlaveDriver::read`]
+    ///
+    /// Returns `Ok(None)` if nothing has been received from the master yet. Received data is
+    /// queued in the driver's RX buffer, so this can simply be polled repeatedly.
+    pub fn try_read(&mut self, buffer: &mut [u8]) -> Result<Option<usize>, EspError> {
+        match self.read(buffer, NON_BLOCK) {
+            Ok(n) => Ok(Some(n)),
+            Err(err) if err.code() == ESP_ERR_TIMEOUT => Ok(None),
+            Err(err) => Err(err),
         }
     }
 
@@ -517,8 +1562,10 @@ impl<'d> I2cSlaveDriver<'d> {
 
         if n > 0 {
             Ok(n as usize)
-        } else {
+        } else if n == 0 {
             Err(EspError::from_infallible::<ESP_ERR_TIMEOUT>())
+        } else {
+            Err(EspError::from_infallible::<ESP_FAIL>())
         }
     }
 
@@ -530,18 +1577,64 @@ impl<'d> I2cSlaveDriver<'d> {
 #[cfg(not(esp32c2))]
 impl<'d> Drop for I2cSlaveDriver<'d> {
     fn drop(&mut self) {
-        esp!(unsafe { i2c_driver_delete(self.port()) }).unwrap();
+        if let Err(err) = esp!(unsafe { i2c_driver_delete(self.port()) }) {
+            ::log::error!(
+                "Failed to delete the I2C slave driver on port {}: {}",
+                self.port(),
+                err
+            );
+        }
+    }
+}
+
+#[derive(Copy, Clone)]
+enum Address {
+    SevenBit(u8),
+    TenBit(u16),
+}
+
+impl Address {
+    /// Rejects addresses which do not fit in their bit width, as they would otherwise
+    /// be silently truncated on the wire
+    fn check(self) -> Result<(), EspError> {
+        let valid = match self {
+            Self::SevenBit(addr) => addr <= 0x7f,
+            Self::TenBit(addr) => addr <= 0x3ff,
+        };
+
+        if valid {
+            Ok(())
+        } else {
+            Err(EspError::from_infallible::<ESP_ERR_INVALID_ARG>())
+        }
     }
 }
 
+/// How the master acknowledges the bytes of a [`RawI2cCommand::Read`]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
 #[repr(u32)]
-enum AckType {
+pub enum AckType {
+    /// ACK every byte
     Ack = i2c_ack_type_t_I2C_MASTER_ACK,
-    #[allow(dead_code)]
+    /// NACK every byte
     Nack = i2c_ack_type_t_I2C_MASTER_NACK,
+    /// ACK every byte except for the last one, which is NACKed
     LastNack = i2c_ack_type_t_I2C_MASTER_LAST_NACK,
 }
 
+/// A low-level command for [`I2cDriver::execute`]
+#[derive(Debug)]
+pub enum RawI2cCommand<'a> {
+    /// A start condition, or a repeated start if the bus is already owned
+    Start,
+    /// Writes the bytes, checking for an ACK after each of them if the flag is set
+    Write(&'a [u8], bool),
+    /// Reads into the buffer, acknowledging the bytes as specified
+    Read(&'a mut [u8], AckType),
+    /// A stop condition
+    Stop,
+}
+
 struct CommandLink<'buffers>(i2c_cmd_handle_t, PhantomData<&'buffers u8>);
 
 impl<'buffers> CommandLink<'buffers> {
@@ -563,6 +1656,40 @@ impl<'buffers> CommandLink<'buffers> {
         esp!(unsafe { i2c_master_stop(self.0) })
     }
 
+    /// Emits a (repeated) start condition followed by the address frame(s) for `address`
+    ///
+    /// A 10-bit address is always sent in full (two bytes in write direction), followed by
+    /// a repeated start and the header byte with the read bit set when `read` is requested.
+    fn master_start_address(&mut self, address: Address, read: bool) -> Result<(), EspError> {
+        address.check()?;
+
+        let rw = if read {
+            i2c_rw_t_I2C_MASTER_READ as u8
+        } else {
+            i2c_rw_t_I2C_MASTER_WRITE as u8
+        };
+
+        self.master_start()?;
+
+        match address {
+            Address::SevenBit(addr) => self.master_write_byte((addr << 1) | rw, true),
+            Address::TenBit(addr) => {
+                // 0b11110 followed by the two address MSBs
+                let header = 0b1111_0000 | (((addr >> 7) as u8) & 0b110);
+
+                self.master_write_byte(header | (i2c_rw_t_I2C_MASTER_WRITE as u8), true)?;
+                self.master_write_byte(addr as u8, true)?;
+
+                if read {
+                    self.master_start()?;
+                    self.master_write_byte(header | rw, true)?;
+                }
+
+                Ok(())
+            }
+        }
+    }
+
     fn master_write_byte(&mut self, data: u8, ack_en: bool) -> Result<(), EspError> {
         esp!(unsafe { i2c_master_write_byte(self.0, data, ack_en) })
     }
@@ -574,6 +1701,14 @@ impl<'buffers> CommandLink<'buffers> {
     fn master_read(&mut self, buf: &'buffers mut [u8], ack: AckType) -> Result<(), EspError> {
         esp!(unsafe { i2c_master_read(self.0, buf.as_mut_ptr().cast(), buf.len(), ack as u32,) })
     }
+
+    fn master_read_uninit(
+        &mut self,
+        buf: &'buffers mut [core::mem::MaybeUninit<u8>],
+        ack: AckType,
+    ) -> Result<(), EspError> {
+        esp!(unsafe { i2c_master_read(self.0, buf.as_mut_ptr().cast(), buf.len(), ack as u32) })
+    }
 }
 
 impl<'buffers> Drop for CommandLink<'buffers> {